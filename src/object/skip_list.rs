@@ -3,25 +3,28 @@ use crate::object::{RobjPtr, Robj, RobjType, Sds};
 use rand::prelude::*;
 use std::iter::Skip;
 use core::borrow::{Borrow, BorrowMut};
-use std::cell::{Ref, RefCell};
+use std::ops::Bound;
+use std::cmp::Ordering;
+use std::ptr::NonNull;
+use std::marker::PhantomData;
 
 const SKIP_LIST_MAX_LEVEL: usize = 32;
 
-pub struct SkipListLevel {
-    forward: Option<Rc<RefCell<SkipListNode>>>,
+pub struct SkipListLevel<K, V> {
+    forward: Option<NonNull<SkipListNode<K, V>>>,
     span: usize,
 }
 
-pub struct SkipListNode {
-    obj: Option<RobjPtr>,
-    score: f64,
-    backward: Option<Rc<RefCell<SkipListNode>>>,
-    level: Vec<SkipListLevel>,
+pub struct SkipListNode<K, V> {
+    key: Option<K>,
+    value: Option<V>,
+    backward: Option<NonNull<SkipListNode<K, V>>>,
+    level: Vec<SkipListLevel<K, V>>,
 }
 
-impl SkipListNode {
-    fn new(level: usize, score: f64, obj: Option<RobjPtr>) -> SkipListNode {
-        let mut level_vec: Vec<SkipListLevel>
+impl<K, V> SkipListNode<K, V> {
+    fn boxed(level: usize, key: Option<K>, value: Option<V>) -> NonNull<SkipListNode<K, V>> {
+        let mut level_vec: Vec<SkipListLevel<K, V>>
             = Vec::with_capacity(level);
 
         for _ in 0..level {
@@ -31,41 +34,37 @@ impl SkipListNode {
             });
         }
 
-        let mut node = SkipListNode {
-            obj: None,
-            score,
+        let node = Box::new(SkipListNode {
+            key,
+            value,
             backward: None,
             level: level_vec,
-        };
+        });
 
-        if let Some(p) = obj {
-            node.obj = Some(p);
-        }
+        // SAFETY: `Box::into_raw` never returns a null pointer.
+        unsafe { NonNull::new_unchecked(Box::into_raw(node)) }
+    }
 
-        node
+    fn key_ref(&self) -> &K {
+        self.key.as_ref().unwrap()
     }
 
-    fn obj_ref(&self) -> &RobjPtr {
-        self.obj.as_ref().unwrap()
+    fn value_ref(&self) -> &V {
+        self.value.as_ref().unwrap()
     }
 }
 
-pub struct SkipList {
-    header: Rc<RefCell<SkipListNode>>,
-    tail: Option<Rc<RefCell<SkipListNode>>>,
+pub struct SkipList<K, V> {
+    header: NonNull<SkipListNode<K, V>>,
+    tail: Option<NonNull<SkipListNode<K, V>>>,
     length: usize,
     level: usize,
 }
 
-impl SkipList {
-    fn new() -> SkipList {
-        let mut header =
-            SkipListNode::new(SKIP_LIST_MAX_LEVEL, 0.0, None);
-
-        header.backward = None;
-
+impl<K: Ord, V> SkipList<K, V> {
+    fn new() -> SkipList<K, V> {
         SkipList {
-            header: Rc::new(RefCell::new(header)),
+            header: SkipListNode::boxed(SKIP_LIST_MAX_LEVEL, None, None),
             tail: None,
             length: 0,
             level: 1,
@@ -88,108 +87,523 @@ impl SkipList {
         SKIP_LIST_MAX_LEVEL
     }
 
-    fn insert(&mut self, score: f64, obj: RobjPtr) {
-        let mut update: Vec<Option<Rc<RefCell<SkipListNode>>>> =
-            Vec::with_capacity(SKIP_LIST_MAX_LEVEL);
+    fn insert(&mut self, key: K, value: V) {
+        // SAFETY: every `NonNull` reachable from `self.header` was produced by
+        // `SkipListNode::boxed` and is owned exclusively by this list (freed
+        // only by `remove`/`Drop`, both of which require `&mut self`), so
+        // `&mut self` here guarantees no other code holds a pointer to a node
+        // we might mutate or that could be freed while we're still walking it.
+        unsafe {
+            let mut update: Vec<Option<NonNull<SkipListNode<K, V>>>> =
+                vec![None; SKIP_LIST_MAX_LEVEL];
+            let mut rank = [0usize; SKIP_LIST_MAX_LEVEL];
+
+            let mut x = self.header;
+
+            for i in (0..self.level).rev() {
+                rank[i] = if i == self.level - 1 {
+                    0
+                } else {
+                    rank[i + 1]
+                };
+
+                loop {
+                    let forward = match x.as_ref().level[i].forward {
+                        None => break,
+                        Some(f) => f,
+                    };
+
+                    if forward.as_ref().key_ref() >= &key {
+                        break;
+                    }
+
+                    rank[i] += x.as_ref().level[i].span;
+                    x = forward;
+                }
 
-        for i in 0..SKIP_LIST_MAX_LEVEL {
-            update.push(None)
-        }
+                update[i] = Some(x);
+            }
+
+            let level = SkipList::<K, V>::random_level();
 
-        let mut rank = [0usize; SKIP_LIST_MAX_LEVEL];
+            if level > self.level {
+                for i in self.level..level {
+                    rank[i] = 0;
+                    update[i] = Some(self.header);
+                    self.header.as_mut().level[i].span = self.length;
+                }
+
+                self.level = level;
+            }
+
+            let mut new_node = SkipListNode::boxed(level, Some(key), Some(value));
+
+            for i in 0..level {
+                let mut prev = update[i].unwrap();
 
-        let mut x = Rc::clone(&self.header);
+                new_node.as_mut().level[i].forward = prev.as_ref().level[i].forward;
+                prev.as_mut().level[i].forward = Some(new_node);
 
-        for i in (0..self.level).rev() {
-            rank[i] = if i == self.level - 1 {
-                0
+                new_node.as_mut().level[i].span
+                    = prev.as_ref().level[i].span - (rank[0] - rank[i]);
+                prev.as_mut().level[i].span = (rank[0] - rank[i]) + 1;
+            }
+
+            for i in level..self.level {
+                update[i].unwrap().as_mut().level[i].span += 1;
+            }
+
+            new_node.as_mut().backward = if update[0].unwrap() == self.header {
+                None
             } else {
-                rank[i + 1]
+                update[0]
             };
 
+            match new_node.as_ref().level[0].forward {
+                Some(mut next) => next.as_mut().backward = Some(new_node),
+                None => self.tail = Some(new_node),
+            }
+
+            self.length += 1;
+        }
+    }
 
-            loop {
-                let curr = Rc::clone(&x);
-                let this_node = curr.as_ref().borrow();
-                if this_node.level[i].forward.is_none() {
-                    break;
+    fn remove(&mut self, key: &K) -> bool {
+        // SAFETY: same invariant as `insert` — `&mut self` means no concurrent
+        // borrow of a node can exist, so it's sound to read every node on the
+        // search path and then free `target`'s `Box` once it has been fully
+        // unlinked from every level.
+        unsafe {
+            let mut update: Vec<Option<NonNull<SkipListNode<K, V>>>> =
+                vec![None; SKIP_LIST_MAX_LEVEL];
+
+            let mut x = self.header;
+
+            for i in (0..self.level).rev() {
+                loop {
+                    let forward = match x.as_ref().level[i].forward {
+                        None => break,
+                        Some(f) => f,
+                    };
+
+                    if forward.as_ref().key_ref() >= key {
+                        break;
+                    }
+
+                    x = forward;
                 }
-                let forward = this_node.level[i]
-                    .forward.as_ref().unwrap();
-                let next_node = forward.as_ref().borrow();
-                let next_score = next_node.score.clone();
-                let next_obj = next_node.obj.as_ref().unwrap().as_ref().borrow();
-
-                if next_score > score || (next_score == score &&
-                    next_obj.string() >= obj.as_ref().borrow().string()) {
-                    break;
+
+                update[i] = Some(x);
+            }
+
+            let target = match update[0].unwrap().as_ref().level[0].forward {
+                None => None,
+                Some(node) if node.as_ref().key_ref() == key => Some(node),
+                Some(_) => None,
+            };
+
+            let target = match target {
+                None => return false,
+                Some(node) => node,
+            };
+
+            for i in 0..self.level {
+                let mut prev = update[i].unwrap();
+
+                if prev.as_ref().level[i].forward == Some(target) {
+                    // `target`'s span is 0 exactly when it is the last node of the list,
+                    // which is an entirely normal case (e.g. removing the highest-scored member).
+                    // Sum the spans before subtracting the removed node so the computation
+                    // never goes through a negative intermediate, instead of computing
+                    // `target.span - 1` on its own and relying on it to wrap correctly.
+                    let merged = prev.as_ref().level[i].span + target.as_ref().level[i].span;
+                    prev.as_mut().level[i].span = merged - 1;
+                    prev.as_mut().level[i].forward = target.as_ref().level[i].forward;
+                } else {
+                    prev.as_mut().level[i].span -= 1;
                 }
+            }
+
+            match target.as_ref().level[0].forward {
+                Some(mut next) => next.as_mut().backward = target.as_ref().backward,
+                None => self.tail = target.as_ref().backward,
+            }
 
-                rank[i] += this_node.level[i].span;
+            self.length -= 1;
 
-                x = Rc::clone(forward);
+            while self.level > 1 &&
+                self.header.as_ref().level[self.level - 1].forward.is_none() {
+                self.level -= 1;
             }
 
-            update[i] = Some(Rc::clone(&x));
+            drop(Box::from_raw(target.as_ptr()));
         }
 
-        let level = SkipList::random_level();
+        true
+    }
 
-        if level > self.level {
-            for i in self.level..level {
-                rank[i] = 0;
-                update[i] = Some(Rc::clone(&self.header));
-                update[i].as_ref().unwrap()
-                    .as_ref().borrow_mut().level[i].span = self.length;
+    /// Looks up `key` and borrows its value — no `V: Clone` bound, no
+    /// allocation on lookup.
+    fn get(&self, key: &K) -> Option<&V> {
+        // SAFETY: every node visited is reachable from `self.header` and this
+        // method only reads through `&self`, so no node can be freed or
+        // mutated for as long as the returned `&V` (which borrows from
+        // `&self` via lifetime elision) is alive.
+        unsafe {
+            let mut x = self.header;
+
+            for i in (0..self.level).rev() {
+                loop {
+                    let forward = match x.as_ref().level[i].forward {
+                        None => break,
+                        Some(f) => f,
+                    };
+
+                    if forward.as_ref().key_ref() >= key {
+                        break;
+                    }
+
+                    x = forward;
+                }
             }
 
-            self.level = level;
+            match x.as_ref().level[0].forward {
+                Some(node) if node.as_ref().key_ref() == key => Some(node.as_ref().value_ref()),
+                _ => None,
+            }
         }
+    }
 
-        let new_node = Rc::new(
-            RefCell::new(
-                SkipListNode::new(level, score, Some(obj))
-            )
-        );
-        let curr = new_node.as_ref();
+    fn get_rank(&self, key: &K) -> Option<usize> {
+        // SAFETY: read-only traversal of nodes reachable from `self.header`;
+        // `&self` prevents any concurrent mutation or free.
+        unsafe {
+            let mut rank = 0usize;
+            let mut x = self.header;
+
+            for i in (0..self.level).rev() {
+                loop {
+                    let forward = match x.as_ref().level[i].forward {
+                        None => break,
+                        Some(f) => f,
+                    };
+
+                    if forward.as_ref().key_ref() >= key {
+                        break;
+                    }
+
+                    rank += x.as_ref().level[i].span;
+                    x = forward;
+                }
+            }
 
-        for i in 0..level {
-            let prev = update[i].as_ref().unwrap().as_ref();
+            match x.as_ref().level[0].forward {
+                Some(node) if node.as_ref().key_ref() == key => Some(rank + 1),
+                _ => None,
+            }
+        }
+    }
 
-            curr.borrow_mut().level[i].forward = match prev.borrow().level[i].forward {
-                None => None,
-                Some(_) => Some(Rc::clone(prev.borrow().level[i]
-                    .forward.as_ref().unwrap())),
-            };
+    fn get_by_rank(&self, rank: usize) -> Option<&V> {
+        if rank == 0 {
+            return None;
+        }
 
-            prev.borrow_mut().level[i].forward = Some(Rc::clone(&new_node));
+        // SAFETY: read-only traversal of nodes reachable from `self.header`;
+        // `&self` prevents any concurrent mutation or free, and the returned
+        // `&V` borrows from `&self` via lifetime elision.
+        unsafe {
+            let mut traversed = 0usize;
+            let mut x = self.header;
+
+            for i in (0..self.level).rev() {
+                loop {
+                    let forward = match x.as_ref().level[i].forward {
+                        None => break,
+                        Some(f) => f,
+                    };
+
+                    if traversed + x.as_ref().level[i].span > rank {
+                        break;
+                    }
+
+                    traversed += x.as_ref().level[i].span;
+                    x = forward;
+                }
 
-            curr.borrow_mut().level[i].span
-                = prev.borrow().level[i].span - (rank[0] - rank[i]);
+                if traversed == rank {
+                    break;
+                }
+            }
 
-            prev.borrow_mut().level[i].span = (rank[0] - rank[i]) + 1;
+            if traversed == rank && x != self.header {
+                Some(x.as_ref().value_ref())
+            } else {
+                None
+            }
         }
+    }
+}
 
-        for i in level..self.level {
-            update[i].as_ref().unwrap().as_ref().borrow_mut().level[i].span += 1;
+impl<K, V> Drop for SkipList<K, V> {
+    fn drop(&mut self) {
+        // SAFETY: this list uniquely owns every node reachable from `header`
+        // (no other code can hold a `NonNull` into it once all iterators
+        // borrowing `self` have ended, since they're bound to `self`'s
+        // lifetime), so walking level 0 and freeing each `Box` exactly once,
+        // then freeing `header` itself, is sound.
+        unsafe {
+            let mut current = self.header.as_ref().level[0].forward;
+            while let Some(node) = current {
+                current = node.as_ref().level[0].forward;
+                drop(Box::from_raw(node.as_ptr()));
+            }
+            drop(Box::from_raw(self.header.as_ptr()));
         }
+    }
+}
 
-        curr.borrow_mut().backward = if Rc::ptr_eq(
-            &self.header, update[0].as_ref().unwrap(),
-        ) {
-            None
-        } else {
-            Some(Rc::clone(update[0].as_ref().unwrap()))
+/// Sort key for a `ZSkipList`: primary order by `score`, ties broken by
+/// member name, matching the ordering `insert` enforced before the
+/// generic split.
+#[derive(Clone)]
+pub struct ZSetKey {
+    pub score: f64,
+    pub member: Sds,
+}
+
+impl PartialEq for ZSetKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score && self.member == other.member
+    }
+}
+
+impl Eq for ZSetKey {}
+
+impl PartialOrd for ZSetKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ZSetKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.partial_cmp(&other.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| self.member.cmp(&other.member))
+    }
+}
+
+/// A float-scored, `Sds`-keyed ordered set of `Robj`s — the instantiation
+/// the rest of reredis uses for ZSET storage.
+pub type ZSkipList = SkipList<ZSetKey, RobjPtr>;
+
+impl ZSkipList {
+    fn first_in_range(
+        &self,
+        min: f64,
+        min_excl: bool,
+        max: f64,
+        max_excl: bool,
+    ) -> Option<NonNull<SkipListNode<ZSetKey, RobjPtr>>> {
+        // SAFETY: read-only traversal of nodes reachable from `self.header`;
+        // `&self` prevents any concurrent mutation or free. The returned
+        // pointer is only ever handed to callers that tie it to `self`'s
+        // lifetime (see `range`).
+        unsafe {
+            let mut x = self.header;
+
+            for i in (0..self.level).rev() {
+                loop {
+                    let forward = match x.as_ref().level[i].forward {
+                        None => break,
+                        Some(f) => f,
+                    };
+                    let next_score = forward.as_ref().key_ref().score;
+
+                    let below_min = if min_excl { next_score <= min } else { next_score < min };
+                    if !below_min {
+                        break;
+                    }
+
+                    x = forward;
+                }
+            }
+
+            match x.as_ref().level[0].forward {
+                None => None,
+                Some(node) => {
+                    let score = node.as_ref().key_ref().score;
+                    let within_max = if max_excl { score < max } else { score <= max };
+                    if within_max {
+                        Some(node)
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+    }
+
+    fn last_in_range(
+        &self,
+        min: f64,
+        min_excl: bool,
+        max: f64,
+        max_excl: bool,
+    ) -> Option<NonNull<SkipListNode<ZSetKey, RobjPtr>>> {
+        // SAFETY: read-only traversal of nodes reachable from `self.header`;
+        // `&self` prevents any concurrent mutation or free. The returned
+        // pointer is only ever handed to callers that tie it to `self`'s
+        // lifetime (see `range`).
+        unsafe {
+            let mut x = self.header;
+
+            for i in (0..self.level).rev() {
+                loop {
+                    let forward = match x.as_ref().level[i].forward {
+                        None => break,
+                        Some(f) => f,
+                    };
+                    let next_score = forward.as_ref().key_ref().score;
+
+                    let within_max = if max_excl { next_score < max } else { next_score <= max };
+                    if !within_max {
+                        break;
+                    }
+
+                    x = forward;
+                }
+            }
+
+            if x == self.header {
+                return None;
+            }
+
+            let score = x.as_ref().key_ref().score;
+            let within_min = if min_excl { score > min } else { score >= min };
+            if within_min {
+                Some(x)
+            } else {
+                None
+            }
+        }
+    }
+
+    fn range(&self, min: Bound<f64>, max: Bound<f64>) -> SkipListRangeIter<'_> {
+        let (min_val, min_excl) = match min {
+            Bound::Unbounded => (f64::NEG_INFINITY, false),
+            Bound::Included(m) => (m, false),
+            Bound::Excluded(m) => (m, true),
+        };
+        let (max_val, max_excl) = match max {
+            Bound::Unbounded => (f64::INFINITY, false),
+            Bound::Included(m) => (m, false),
+            Bound::Excluded(m) => (m, true),
         };
 
-        if let Some(e) = curr.borrow().level[0].forward.as_ref() {
-            e.as_ref().borrow_mut().backward = Some(Rc::clone(&new_node));
-        } else {
-            self.tail = Some(Rc::clone(&new_node));
+        SkipListRangeIter {
+            current: self.first_in_range(min_val, min_excl, max_val, max_excl),
+            max,
+            _marker: PhantomData,
         }
+    }
 
-        self.length += 1;
+    fn iter(&self) -> SkipListIter<'_> {
+        SkipListIter {
+            // SAFETY: `&self` guarantees the list outlives the borrow below,
+            // and the lifetime on `SkipListIter` ties every pointer it yields
+            // to that same borrow.
+            current: unsafe { self.header.as_ref().level[0].forward },
+            _marker: PhantomData,
+        }
+    }
 
+    fn iter_rev(&self) -> SkipListRevIter<'_> {
+        SkipListRevIter {
+            current: self.tail,
+            _marker: PhantomData,
+        }
+    }
+}
+
+pub struct SkipListIter<'a> {
+    current: Option<NonNull<SkipListNode<ZSetKey, RobjPtr>>>,
+    _marker: PhantomData<&'a ZSkipList>,
+}
+
+impl<'a> Iterator for SkipListIter<'a> {
+    type Item = (f64, RobjPtr);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current.take()?;
+        // SAFETY: `node` was produced from a list borrowed for `'a` (see
+        // `SkipList::iter`), and this iterator's own `'a` marker keeps that
+        // borrow — and therefore every node on its forward chain — alive for
+        // as long as the iterator exists.
+        unsafe {
+            let node_ref = node.as_ref();
+            let item = (node_ref.key_ref().score, Rc::clone(node_ref.value_ref()));
+            self.current = node_ref.level[0].forward;
+            Some(item)
+        }
+    }
+}
+
+pub struct SkipListRevIter<'a> {
+    current: Option<NonNull<SkipListNode<ZSetKey, RobjPtr>>>,
+    _marker: PhantomData<&'a ZSkipList>,
+}
+
+impl<'a> Iterator for SkipListRevIter<'a> {
+    type Item = (f64, RobjPtr);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current.take()?;
+        // SAFETY: same invariant as `SkipListIter::next` — the `'a` marker
+        // keeps the borrowed list (and every node reachable via `backward`)
+        // alive for the iterator's lifetime.
+        unsafe {
+            let node_ref = node.as_ref();
+            let item = (node_ref.key_ref().score, Rc::clone(node_ref.value_ref()));
+            self.current = node_ref.backward;
+            Some(item)
+        }
+    }
+}
+
+pub struct SkipListRangeIter<'a> {
+    current: Option<NonNull<SkipListNode<ZSetKey, RobjPtr>>>,
+    max: Bound<f64>,
+    _marker: PhantomData<&'a ZSkipList>,
+}
+
+impl<'a> Iterator for SkipListRangeIter<'a> {
+    type Item = (f64, RobjPtr);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current.take()?;
+        // SAFETY: same invariant as `SkipListIter::next` — the `'a` marker
+        // keeps the borrowed list (and every node reachable via `forward`)
+        // alive for the iterator's lifetime.
+        unsafe {
+            let node_ref = node.as_ref();
+            let score = node_ref.key_ref().score;
+
+            let in_range = match self.max {
+                Bound::Unbounded => true,
+                Bound::Included(m) => score <= m,
+                Bound::Excluded(m) => score < m,
+            };
+            if !in_range {
+                return None;
+            }
+
+            let obj = Rc::clone(node_ref.value_ref());
+            self.current = node_ref.level[0].forward;
+
+            Some((score, obj))
+        }
     }
 }
 
@@ -199,9 +613,16 @@ mod test {
     use super::*;
     use crate::object::{Robj, RobjPtr};
 
+    fn key(score: f64, member: &str) -> ZSetKey {
+        ZSetKey {
+            score,
+            member: Robj::create_string_object(member).as_ref().borrow().string().clone(),
+        }
+    }
+
     #[test]
     fn create_new_skip_list() {
-        let list = SkipList::new();
+        let list: ZSkipList = SkipList::new();
         assert_eq!(list.length, 0);
         assert_eq!(list.level, 1);
     }
@@ -210,7 +631,7 @@ mod test {
     fn generate_rand_level() {
         let mut levels = vec![0usize; 33];
         for i in 0..100000 {
-            let l = SkipList::random_level();
+            let l = ZSkipList::random_level();
             levels[l] += 1;
         }
 
@@ -222,13 +643,109 @@ mod test {
 
     #[test]
     fn simple_insert() {
-        let mut list = SkipList::new();
+        let mut list: ZSkipList = SkipList::new();
         let o1 = Robj::create_string_object("foo");
         let o2 = Robj::create_string_object("bar");
 
-        list.insert(3.2, o1);
-        list.insert(0.2, o2);
+        list.insert(key(3.2, "foo"), o1);
+        list.insert(key(0.2, "bar"), o2);
     }
-}
 
+    #[test]
+    fn delete_existing_member() {
+        let mut list: ZSkipList = SkipList::new();
+        let o1 = Robj::create_string_object("foo");
+        let o2 = Robj::create_string_object("bar");
+
+        list.insert(key(3.2, "foo"), Rc::clone(&o1));
+        list.insert(key(0.2, "bar"), Rc::clone(&o2));
+
+        assert!(list.remove(&key(3.2, "foo")));
+        assert_eq!(list.length, 1);
+        assert!(!list.remove(&key(3.2, "foo")));
+    }
+
+    #[test]
+    fn delete_missing_member_is_noop() {
+        let mut list: ZSkipList = SkipList::new();
+        let o1 = Robj::create_string_object("foo");
+
+        list.insert(key(3.2, "foo"), Rc::clone(&o1));
+
+        assert!(!list.remove(&key(1.0, "foo")));
+        assert_eq!(list.length, 1);
+    }
 
+    #[test]
+    fn rank_and_by_rank_round_trip() {
+        let mut list: ZSkipList = SkipList::new();
+        let o1 = Robj::create_string_object("a");
+        let o2 = Robj::create_string_object("b");
+        let o3 = Robj::create_string_object("c");
+
+        list.insert(key(1.0, "a"), Rc::clone(&o1));
+        list.insert(key(2.0, "b"), Rc::clone(&o2));
+        list.insert(key(3.0, "c"), Rc::clone(&o3));
+
+        assert_eq!(list.get_rank(&key(1.0, "a")), Some(1));
+        assert_eq!(list.get_rank(&key(2.0, "b")), Some(2));
+        assert_eq!(list.get_rank(&key(3.0, "c")), Some(3));
+        assert_eq!(list.get_rank(&key(4.0, "c")), None);
+
+        assert!(Rc::ptr_eq(list.get_by_rank(1).unwrap(), &o1));
+        assert!(Rc::ptr_eq(list.get_by_rank(3).unwrap(), &o3));
+        assert!(list.get_by_rank(0).is_none());
+        assert!(list.get_by_rank(4).is_none());
+    }
+
+    #[test]
+    fn get_returns_value_for_key() {
+        let mut list: ZSkipList = SkipList::new();
+        let o1 = Robj::create_string_object("foo");
+
+        list.insert(key(3.2, "foo"), Rc::clone(&o1));
+
+        assert!(Rc::ptr_eq(list.get(&key(3.2, "foo")).unwrap(), &o1));
+        assert!(list.get(&key(1.0, "foo")).is_none());
+    }
+
+    #[test]
+    fn range_by_score_respects_bounds() {
+        let mut list: ZSkipList = SkipList::new();
+        for (score, name) in [(1.0, "a"), (2.0, "b"), (3.0, "c"), (4.0, "d")] {
+            list.insert(key(score, name), Robj::create_string_object(name));
+        }
+
+        let all: Vec<f64> = list.range(Bound::Unbounded, Bound::Unbounded)
+            .map(|(score, _)| score)
+            .collect();
+        assert_eq!(all, vec![1.0, 2.0, 3.0, 4.0]);
+
+        let inclusive: Vec<f64> = list.range(Bound::Included(2.0), Bound::Included(3.0))
+            .map(|(score, _)| score)
+            .collect();
+        assert_eq!(inclusive, vec![2.0, 3.0]);
+
+        let exclusive: Vec<f64> = list.range(Bound::Excluded(1.0), Bound::Excluded(4.0))
+            .map(|(score, _)| score)
+            .collect();
+        assert_eq!(exclusive, vec![2.0, 3.0]);
+
+        assert!(list.first_in_range(1.0, true, 4.0, true).is_some());
+        assert!(list.last_in_range(10.0, false, 20.0, false).is_none());
+    }
+
+    #[test]
+    fn forward_and_reverse_iteration() {
+        let mut list: ZSkipList = SkipList::new();
+        for (score, name) in [(1.0, "a"), (2.0, "b"), (3.0, "c")] {
+            list.insert(key(score, name), Robj::create_string_object(name));
+        }
+
+        let forward: Vec<f64> = list.iter().map(|(score, _)| score).collect();
+        assert_eq!(forward, vec![1.0, 2.0, 3.0]);
+
+        let reverse: Vec<f64> = list.iter_rev().map(|(score, _)| score).collect();
+        assert_eq!(reverse, vec![3.0, 2.0, 1.0]);
+    }
+}